@@ -99,7 +99,20 @@ fn run() -> hwinfo::Result<()> {
             println!("  - Disk ID {}: {} ({})", disk.id, disk.model, disk.vendor);
             println!("    Serial: {}", disk.serial_number);
             println!("    Size: {:.2} GB", bytes_to_gb(disk.size_bytes));
-            println!("    Volumes: {}", disk.volumes.join(", "));
+            println!("    Kind: {:?} (removable: {})", disk.kind, disk.is_removable);
+            if disk.volumes.is_empty() {
+                println!("    Volumes: none");
+            } else {
+                for volume in &disk.volumes {
+                    println!(
+                        "    - {} ({}): {:.2} GB free of {:.2} GB",
+                        volume.mount_point,
+                        volume.file_system,
+                        bytes_to_gb(volume.available_bytes),
+                        bytes_to_gb(volume.total_bytes)
+                    );
+                }
+            }
         }
     }
 
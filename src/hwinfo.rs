@@ -1,4 +1,5 @@
 use crate::bindings;
+use std::collections::HashMap;
 use std::convert::TryFrom;
 use std::ffi::CStr;
 use std::fmt;
@@ -65,6 +66,7 @@ unsafe fn c_string_array_to_vec(arr: &bindings::C_StringArray) -> Result<Vec<Str
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Cpu {
     pub id: i32,
     pub vendor: String,
@@ -166,6 +168,7 @@ pub fn cpu_thread_speeds_mhz(cpu_id: i32) -> Result<Vec<i64>> {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Os {
     pub name: String,
     pub version: String,
@@ -204,6 +207,7 @@ pub fn os_info() -> Result<Os> {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Gpu {
     pub id: i32,
     pub vendor: String,
@@ -254,7 +258,106 @@ pub fn gpus() -> Result<Vec<Gpu>> {
     }
 }
 
+/// A clock domain queried by [`gpu_clock_mhz`], matching the domains the
+/// NVML device API exposes per GPU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockDomain {
+    Graphics,
+    Memory,
+    SM,
+    Video,
+}
+
+impl ClockDomain {
+    fn as_c_int(self) -> i32 {
+        match self {
+            ClockDomain::Graphics => 0,
+            ClockDomain::Memory => 1,
+            ClockDomain::SM => 2,
+            ClockDomain::Video => 3,
+        }
+    }
+}
+
+/// Core utilization of `gpu_id` as a 0.0-1.0 busy fraction. Mirrors
+/// `cpu_utilization`: an unsupported GPU simply reports 0.0 rather than an
+/// error.
+pub fn gpu_utilization(gpu_id: i32) -> f64 {
+    unsafe { bindings::get_gpu_utilization(gpu_id) }
+}
+
+/// Bytes of VRAM currently in use on `gpu_id`.
+pub fn gpu_memory_used_bytes(gpu_id: i32) -> Result<i64> {
+    unsafe {
+        let value = bindings::get_gpu_memory_used_bytes(gpu_id);
+        if value < 0 {
+            return Err(HwinfoError::DataUnavailable(format!(
+                "get_gpu_memory_used_bytes for gpu_id {}",
+                gpu_id
+            )));
+        }
+        Ok(value)
+    }
+}
+
+/// Core temperature of `gpu_id` in degrees Celsius.
+pub fn gpu_temperature_celsius(gpu_id: i32) -> Result<f64> {
+    unsafe {
+        let value = bindings::get_gpu_temperature_celsius(gpu_id);
+        if value.is_nan() {
+            return Err(HwinfoError::DataUnavailable(format!(
+                "get_gpu_temperature_celsius for gpu_id {}",
+                gpu_id
+            )));
+        }
+        Ok(value)
+    }
+}
+
+/// Board power draw of `gpu_id` in milliwatts.
+pub fn gpu_power_usage_milliwatts(gpu_id: i32) -> Result<u32> {
+    unsafe {
+        let value = bindings::get_gpu_power_usage_milliwatts(gpu_id);
+        if value == u32::MAX {
+            return Err(HwinfoError::DataUnavailable(format!(
+                "get_gpu_power_usage_milliwatts for gpu_id {}",
+                gpu_id
+            )));
+        }
+        Ok(value)
+    }
+}
+
+/// Fan speed of `gpu_id` as a percentage of maximum.
+pub fn gpu_fan_speed_percent(gpu_id: i32) -> Result<u32> {
+    unsafe {
+        let value = bindings::get_gpu_fan_speed_percent(gpu_id);
+        if value == u32::MAX {
+            return Err(HwinfoError::DataUnavailable(format!(
+                "get_gpu_fan_speed_percent for gpu_id {}",
+                gpu_id
+            )));
+        }
+        Ok(value)
+    }
+}
+
+/// Current clock speed of `gpu_id`'s `domain` in MHz.
+pub fn gpu_clock_mhz(gpu_id: i32, domain: ClockDomain) -> Result<i64> {
+    unsafe {
+        let value = bindings::get_gpu_clock_mhz(gpu_id, domain.as_c_int());
+        if value < 0 {
+            return Err(HwinfoError::DataUnavailable(format!(
+                "get_gpu_clock_mhz({:?}) for gpu_id {}",
+                domain, gpu_id
+            )));
+        }
+        Ok(value)
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct RamModule {
     pub id: i32,
     pub vendor: String,
@@ -283,6 +386,7 @@ impl TryFrom<&bindings::C_RAM_Module> for RamModule {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MemoryInfo {
     pub total_bytes: i64,
     pub free_bytes: i64,
@@ -325,6 +429,7 @@ pub fn memory_info() -> Result<MemoryInfo> {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct MainBoard {
     pub vendor: String,
     pub name: String,
@@ -358,7 +463,56 @@ pub fn mainboard_info() -> Result<MainBoard> {
     }
 }
 
+/// The physical medium backing a [`Disk`], as reported by the OS (rotational
+/// flag on Linux, IOKit on macOS, volume APIs on Windows).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DiskKind {
+    Hdd,
+    Ssd,
+    Nvme,
+    Removable,
+    Unknown,
+}
+
+impl From<i32> for DiskKind {
+    fn from(value: i32) -> Self {
+        match value {
+            0 => DiskKind::Hdd,
+            1 => DiskKind::Ssd,
+            2 => DiskKind::Nvme,
+            3 => DiskKind::Removable,
+            _ => DiskKind::Unknown,
+        }
+    }
+}
+
+/// A single mounted filesystem on a [`Disk`].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Volume {
+    pub mount_point: String,
+    pub file_system: String,
+    pub total_bytes: i64,
+    pub available_bytes: i64,
+}
+
+impl TryFrom<&bindings::C_Volume> for Volume {
+    type Error = HwinfoError;
+    fn try_from(c_volume: &bindings::C_Volume) -> Result<Self> {
+        unsafe {
+            Ok(Volume {
+                mount_point: c_char_to_string(c_volume.mountPoint)?,
+                file_system: c_char_to_string(c_volume.fileSystem)?,
+                total_bytes: c_volume.total_Bytes,
+                available_bytes: c_volume.available_Bytes,
+            })
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Disk {
     pub id: i32,
     pub vendor: String,
@@ -366,12 +520,24 @@ pub struct Disk {
     pub serial_number: String,
     pub size_bytes: i64,
     pub free_size_bytes: i64,
-    pub volumes: Vec<String>,
+    pub kind: DiskKind,
+    pub is_removable: bool,
+    pub volumes: Vec<Volume>,
 }
 
 impl TryFrom<&bindings::C_Disk> for Disk {
     type Error = HwinfoError;
     fn try_from(c_disk: &bindings::C_Disk) -> Result<Self> {
+        let volumes = if c_disk.volumes.is_null() || c_disk.volume_count <= 0 {
+            Vec::new()
+        } else {
+            unsafe {
+                std::slice::from_raw_parts(c_disk.volumes, c_disk.volume_count as usize)
+                    .iter()
+                    .map(Volume::try_from)
+                    .collect::<Result<Vec<Volume>>>()?
+            }
+        };
         unsafe {
             Ok(Disk {
                 id: c_disk.id,
@@ -380,7 +546,9 @@ impl TryFrom<&bindings::C_Disk> for Disk {
                 serial_number: c_char_to_string(c_disk.serialNumber)?,
                 size_bytes: c_disk.size_Bytes,
                 free_size_bytes: c_disk.free_size_Bytes,
-                volumes: c_string_array_to_vec(&c_disk.volumes)?,
+                kind: DiskKind::from(c_disk.kind),
+                is_removable: c_disk.isRemovable,
+                volumes,
             })
         }
     }
@@ -406,6 +574,7 @@ pub fn disks() -> Result<Vec<Disk>> {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Battery {
     pub id: i32,
     pub vendor: String,
@@ -455,12 +624,19 @@ pub fn batteries() -> Result<Vec<Battery>> {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Network {
     pub interface_index: String,
     pub description: String,
     pub mac_address: String,
     pub ipv4_address: String,
     pub ipv6_address: String,
+    pub bytes_received: u64,
+    pub bytes_transmitted: u64,
+    pub packets_received: u64,
+    pub packets_transmitted: u64,
+    pub errors_on_received: u64,
+    pub errors_on_transmitted: u64,
 }
 
 impl TryFrom<&bindings::C_Network> for Network {
@@ -473,6 +649,12 @@ impl TryFrom<&bindings::C_Network> for Network {
                 mac_address: c_char_to_string(c_net.mac)?,
                 ipv4_address: c_char_to_string(c_net.ip4)?,
                 ipv6_address: c_char_to_string(c_net.ip6)?,
+                bytes_received: c_net.bytesReceived,
+                bytes_transmitted: c_net.bytesTransmitted,
+                packets_received: c_net.packetsReceived,
+                packets_transmitted: c_net.packetsTransmitted,
+                errors_on_received: c_net.errorsOnReceived,
+                errors_on_transmitted: c_net.errorsOnTransmitted,
             })
         }
     }
@@ -496,3 +678,324 @@ pub fn networks() -> Result<Vec<Network>> {
         result
     }
 }
+
+#[derive(Debug, Clone, Copy, Default)]
+struct NetworkCounters {
+    bytes_received: u64,
+    bytes_transmitted: u64,
+    packets_received: u64,
+    packets_transmitted: u64,
+    errors_on_received: u64,
+    errors_on_transmitted: u64,
+}
+
+impl From<&Network> for NetworkCounters {
+    fn from(net: &Network) -> Self {
+        NetworkCounters {
+            bytes_received: net.bytes_received,
+            bytes_transmitted: net.bytes_transmitted,
+            packets_received: net.packets_received,
+            packets_transmitted: net.packets_transmitted,
+            errors_on_received: net.errors_on_received,
+            errors_on_transmitted: net.errors_on_transmitted,
+        }
+    }
+}
+
+/// Tracks per-interface network throughput across successive refreshes.
+///
+/// `Network`'s counters are cumulative since boot, so they're only
+/// meaningful as a rate. `NetworkTracker` keeps the previous counter
+/// snapshot per interface (keyed by `interface_index`) and, after each
+/// [`NetworkTracker::refresh`], exposes the delta since the last one via
+/// [`NetworkTracker::received_delta`] / [`NetworkTracker::transmitted_delta`],
+/// mirroring how `sysinfo`'s `NetworkData` derives per-tick traffic. A
+/// missing previous snapshot (first refresh, or the interface just
+/// appeared) reports a delta of zero, and a counter that wrapped or reset
+/// (e.g. an adapter reconnecting) saturates to zero instead of underflowing.
+#[derive(Debug, Clone, Default)]
+pub struct NetworkTracker {
+    previous: HashMap<String, NetworkCounters>,
+    current: HashMap<String, NetworkCounters>,
+}
+
+impl NetworkTracker {
+    pub fn new() -> Self {
+        NetworkTracker::default()
+    }
+
+    /// Re-queries network interfaces and rotates the current snapshot into
+    /// `previous` so the delta accessors reflect this tick.
+    pub fn refresh(&mut self) -> Result<()> {
+        let networks = networks()?;
+        self.previous = std::mem::take(&mut self.current);
+        self.current = networks
+            .iter()
+            .map(|net| (net.interface_index.clone(), NetworkCounters::from(net)))
+            .collect();
+        Ok(())
+    }
+
+    /// Bytes received by `interface_index` since the previous refresh.
+    pub fn received_delta(&self, interface_index: &str) -> u64 {
+        self.delta(interface_index, |c| c.bytes_received)
+    }
+
+    /// Bytes transmitted by `interface_index` since the previous refresh.
+    pub fn transmitted_delta(&self, interface_index: &str) -> u64 {
+        self.delta(interface_index, |c| c.bytes_transmitted)
+    }
+
+    /// Packets received by `interface_index` since the previous refresh.
+    pub fn packets_received_delta(&self, interface_index: &str) -> u64 {
+        self.delta(interface_index, |c| c.packets_received)
+    }
+
+    /// Packets transmitted by `interface_index` since the previous refresh.
+    pub fn packets_transmitted_delta(&self, interface_index: &str) -> u64 {
+        self.delta(interface_index, |c| c.packets_transmitted)
+    }
+
+    /// Receive errors on `interface_index` since the previous refresh.
+    pub fn errors_on_received_delta(&self, interface_index: &str) -> u64 {
+        self.delta(interface_index, |c| c.errors_on_received)
+    }
+
+    /// Transmit errors on `interface_index` since the previous refresh.
+    pub fn errors_on_transmitted_delta(&self, interface_index: &str) -> u64 {
+        self.delta(interface_index, |c| c.errors_on_transmitted)
+    }
+
+    fn delta(&self, interface_index: &str, field: impl Fn(&NetworkCounters) -> u64) -> u64 {
+        let current = match self.current.get(interface_index) {
+            Some(counters) => field(counters),
+            None => return 0,
+        };
+        let previous = self
+            .previous
+            .get(interface_index)
+            .map(field)
+            .unwrap_or(current);
+        current.saturating_sub(previous)
+    }
+}
+
+/// A single thermal sensor reading (CPU, GPU, or board temperature).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Component {
+    pub label: String,
+    pub temperature: f32,
+    pub max: f32,
+    pub critical: Option<f32>,
+}
+
+impl TryFrom<&bindings::C_Component> for Component {
+    type Error = HwinfoError;
+    fn try_from(c_component: &bindings::C_Component) -> Result<Self> {
+        unsafe {
+            Ok(Component {
+                label: c_char_to_string(c_component.label)?,
+                temperature: c_component.temperature,
+                max: c_component.max,
+                critical: if c_component.hasCritical {
+                    Some(c_component.critical)
+                } else {
+                    None
+                },
+            })
+        }
+    }
+}
+
+pub fn components() -> Result<Vec<Component>> {
+    unsafe {
+        let count = bindings::get_component_count();
+        if count <= 0 {
+            return Ok(Vec::new());
+        }
+        let components_ptr = bindings::get_all_components();
+        if components_ptr.is_null() {
+            return Err(HwinfoError::DataUnavailable("get_all_components".into()));
+        }
+        let result = std::slice::from_raw_parts(components_ptr, count as usize)
+            .iter()
+            .map(Component::try_from)
+            .collect();
+        bindings::free_component_info(components_ptr, count);
+        result
+    }
+}
+
+/// A cached handle to the host system's hardware information.
+///
+/// Every free function in this module (`cpus()`, `memory_info()`, `gpus()`, ...)
+/// does a full FFI round-trip that allocates and frees a fresh C struct on
+/// each call. `System` instead keeps the last-fetched data around and only
+/// re-queries the pieces you ask it to, which is the right shape for a
+/// monitor loop that polls hardware repeatedly. The API mirrors the
+/// `sysinfo` crate: build one with [`System::new`] or [`System::new_all`],
+/// keep it alive for the life of your program, and call the `refresh_*`
+/// methods on each tick.
+#[derive(Debug, Clone, Default)]
+pub struct System {
+    cpus: Vec<Cpu>,
+    memory: Option<MemoryInfo>,
+    gpus: Vec<Gpu>,
+    disks: Vec<Disk>,
+    batteries: Vec<Battery>,
+    networks: Vec<Network>,
+}
+
+impl System {
+    /// Creates a `System` with nothing cached yet. Call `refresh_*` (or
+    /// [`System::refresh_all`]) before reading from the accessors.
+    pub fn new() -> Self {
+        System::default()
+    }
+
+    /// Creates a `System` and immediately populates every cached field.
+    pub fn new_all() -> Self {
+        let mut system = System::new();
+        system.refresh_all();
+        system
+    }
+
+    /// Re-queries CPU information and overwrites the cached value. The C
+    /// side hands back vendor/model identity and clock speeds together, so
+    /// this refetches the whole `Cpu` list rather than splitting static and
+    /// dynamic fields.
+    pub fn refresh_cpu(&mut self) {
+        if let Ok(cpus) = cpus() {
+            self.cpus = cpus;
+        }
+    }
+
+    /// Re-queries memory information and overwrites the cached value.
+    pub fn refresh_memory(&mut self) {
+        if let Ok(memory) = memory_info() {
+            self.memory = Some(memory);
+        }
+    }
+
+    /// Re-queries GPU information and overwrites the cached value.
+    pub fn refresh_gpu(&mut self) {
+        if let Ok(gpus) = gpus() {
+            self.gpus = gpus;
+        }
+    }
+
+    /// Re-queries disk information and overwrites the cached value.
+    pub fn refresh_disks(&mut self) {
+        if let Ok(disks) = disks() {
+            self.disks = disks;
+        }
+    }
+
+    /// Re-queries battery information and overwrites the cached value.
+    pub fn refresh_batteries(&mut self) {
+        if let Ok(batteries) = batteries() {
+            self.batteries = batteries;
+        }
+    }
+
+    /// Re-queries network interface information and overwrites the cached value.
+    pub fn refresh_networks(&mut self) {
+        if let Ok(networks) = networks() {
+            self.networks = networks;
+        }
+    }
+
+    /// Refreshes every cached subsystem.
+    pub fn refresh_all(&mut self) {
+        self.refresh_cpu();
+        self.refresh_memory();
+        self.refresh_gpu();
+        self.refresh_disks();
+        self.refresh_batteries();
+        self.refresh_networks();
+    }
+
+    /// Returns the cached CPUs as of the last [`System::refresh_cpu`] (or
+    /// [`System::refresh_all`]) call.
+    pub fn cpus(&self) -> &[Cpu] {
+        &self.cpus
+    }
+
+    /// Returns the cached memory info as of the last [`System::refresh_memory`]
+    /// (or [`System::refresh_all`]) call, if it has been fetched.
+    pub fn memory(&self) -> Option<&MemoryInfo> {
+        self.memory.as_ref()
+    }
+
+    /// Returns the cached GPUs as of the last [`System::refresh_gpu`] (or
+    /// [`System::refresh_all`]) call.
+    pub fn gpus(&self) -> &[Gpu] {
+        &self.gpus
+    }
+
+    /// Returns the cached disks as of the last [`System::refresh_disks`] (or
+    /// [`System::refresh_all`]) call.
+    pub fn disks(&self) -> &[Disk] {
+        &self.disks
+    }
+
+    /// Returns the cached batteries as of the last [`System::refresh_batteries`]
+    /// (or [`System::refresh_all`]) call.
+    pub fn batteries(&self) -> &[Battery] {
+        &self.batteries
+    }
+
+    /// Returns the cached network interfaces as of the last
+    /// [`System::refresh_networks`] (or [`System::refresh_all`]) call.
+    pub fn networks(&self) -> &[Network] {
+        &self.networks
+    }
+}
+
+/// A single, self-contained capture of every subsystem this crate reports,
+/// suitable for feeding a dashboard or inventory pipeline. Enable the
+/// `serde` feature to make `Snapshot` (and every struct it contains)
+/// serializable, so captures can be exported as JSON and diffed across runs.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Snapshot {
+    pub os: Os,
+    pub mainboard: MainBoard,
+    pub memory: MemoryInfo,
+    pub cpus: Vec<Cpu>,
+    pub gpus: Vec<Gpu>,
+    pub disks: Vec<Disk>,
+    pub batteries: Vec<Battery>,
+    pub networks: Vec<Network>,
+    pub components: Vec<Component>,
+}
+
+impl Snapshot {
+    /// Gathers every subsystem in one pass.
+    pub fn capture() -> Result<Snapshot> {
+        Ok(Snapshot {
+            os: os_info()?,
+            mainboard: mainboard_info()?,
+            memory: memory_info()?,
+            cpus: cpus()?,
+            gpus: gpus()?,
+            disks: disks()?,
+            batteries: batteries()?,
+            networks: networks()?,
+            components: components()?,
+        })
+    }
+
+    /// Serializes this snapshot as compact JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string(self)
+    }
+
+    /// Serializes this snapshot as pretty-printed JSON.
+    #[cfg(feature = "serde")]
+    pub fn to_json_pretty(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}